@@ -0,0 +1,182 @@
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read};
+
+use thiserror::Error;
+
+use crate::uulev1::Uulev1Data;
+
+/// Geotargets resolves a free-form place query into the exact `canonical_name` string UULEv1
+/// requires, loaded from Google's geotarget table (the CSV referenced in the crate docs, see
+/// <https://developers.google.com/google-ads/api/data/geotargets>).
+///
+/// When an exact canonical-name match isn't found, [`Geotargets::resolve`] falls back the way
+/// ICU's locale lookup does: it progressively drops the most specific comma-separated component
+/// (`"Queens County,New York,United States"` -> `"New York,United States"` -> `"United States"`)
+/// until it finds a matching parent geotarget.
+///
+/// # Examples
+///
+/// ```
+/// use uule_converter::geotargets::{Geotargets, GeotargetMatch};
+/// use uule_converter::uulev1::Uulev1Data;
+///
+/// // The Canonical Name column is quoted because it contains commas of its own.
+/// let csv = "Criteria ID,Name,Canonical Name,Parent ID,Country Code,Target Type,Status\n\
+/// 1014044,United States,United States,0,US,Country,Active\n\
+/// 21167,New York,\"New York,United States\",1014044,US,State,Active\n";
+///
+/// let geotargets = Geotargets::from_csv_reader(csv.as_bytes()).unwrap();
+///
+/// // Exact canonical-name match
+/// let exact = geotargets.resolve("New York,United States").unwrap();
+/// assert_eq!(exact, GeotargetMatch { canonical_name: "New York,United States".to_string(), criteria_id: 21167, country_code: "US".to_string(), target_type: "State".to_string() });
+///
+/// // "Queens County,New York,United States" isn't a row in the table, so the most specific
+/// // component is dropped until "New York,United States" matches.
+/// let fallback = geotargets.resolve("Queens County,New York,United States").unwrap();
+/// assert_eq!(fallback, exact);
+///
+/// // No component of the query matches any geotarget
+/// assert!(geotargets.resolve("Nowhereville").is_none());
+///
+/// // Uulev1Data::from_geotarget uses the resolved canonical name directly
+/// let uule = Uulev1Data::from_geotarget(&exact);
+/// assert_eq!(uule.canonical_name, "New York,United States".to_string());
+/// ```
+///
+/// Malformed rows are reported rather than silently skipped:
+///
+/// ```
+/// use uule_converter::geotargets::{Geotargets, GeotargetsError};
+///
+/// let csv = "Criteria ID,Name,Canonical Name,Parent ID,Country Code,Target Type,Status\n\
+/// not-a-number,New York,New York,1014044,US,State,Active\n";
+/// let error = Geotargets::from_csv_reader(csv.as_bytes()).unwrap_err();
+/// assert!(matches!(error, GeotargetsError::InvalidCriteriaId { .. }));
+///
+/// let csv = "Criteria ID,Name,Canonical Name,Parent ID,Country Code,Target Type,Status\n\
+/// 21167\n";
+/// let error = Geotargets::from_csv_reader(csv.as_bytes()).unwrap_err();
+/// assert!(matches!(error, GeotargetsError::MalformedRow(1)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Geotargets {
+    by_canonical_name: HashMap<String, GeotargetMatch>,
+}
+
+impl Geotargets {
+    /// Build a [`Geotargets`] table from a reader over Google's geotarget CSV. The first line is
+    /// assumed to be the header (`Criteria ID,Name,Canonical Name,Parent ID,Country Code,Target
+    /// Type,Status`) and is skipped.
+    pub fn from_csv_reader<R: Read>(r: R) -> Result<Self, GeotargetsError> {
+        let mut by_canonical_name = HashMap::new();
+
+        for (i, line) in BufReader::new(r).lines().enumerate() {
+            let line = line?;
+            if i == 0 || line.is_empty() {
+                continue;
+            }
+
+            let fields = parse_csv_line(&line);
+            let criteria_id: u64 = fields
+                .first()
+                .ok_or(GeotargetsError::MalformedRow(i))?
+                .parse()
+                .map_err(|source| GeotargetsError::InvalidCriteriaId { source })?;
+            let canonical_name = fields.get(2).ok_or(GeotargetsError::MalformedRow(i))?.clone();
+            let country_code = fields.get(4).ok_or(GeotargetsError::MalformedRow(i))?.clone();
+            let target_type = fields.get(5).ok_or(GeotargetsError::MalformedRow(i))?.clone();
+
+            by_canonical_name.insert(
+                canonical_name.clone(),
+                GeotargetMatch { canonical_name, criteria_id, country_code, target_type },
+            );
+        }
+
+        Ok(Self { by_canonical_name })
+    }
+
+    /// Resolve a free-form place query into the nearest matching geotarget. If `query` isn't an
+    /// exact canonical name, the most specific comma-separated component is dropped and the
+    /// lookup is retried against the resulting parent, repeating until a match is found or no
+    /// components remain.
+    pub fn resolve(&self, query: &str) -> Option<GeotargetMatch> {
+        let mut candidate = query;
+        loop {
+            if let Some(found) = self.by_canonical_name.get(candidate) {
+                return Some(found.clone());
+            }
+            match candidate.split_once(',') {
+                Some((_, parent)) => candidate = parent,
+                None => return None,
+            }
+        }
+    }
+}
+
+/// A geotarget resolved by [`Geotargets::resolve`], carrying enough information for callers to
+/// confirm the fallback actually landed where they intended.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeotargetMatch {
+    /// The exact canonical name UULEv1 expects, e.g. `"New York,United States"`
+    pub canonical_name: String,
+    /// Google's criteria ID for this geotarget
+    pub criteria_id: u64,
+    /// ISO country code, e.g. `"US"`
+    pub country_code: String,
+    /// The geotarget's type, e.g. `"Country"`, `"State"`, or `"City"`
+    pub target_type: String,
+}
+
+impl Uulev1Data {
+    /// Construct a [`Uulev1Data`] from a resolved [`GeotargetMatch`], using its canonical name.
+    pub fn from_geotarget(geotarget: &GeotargetMatch) -> Self {
+        Self::new(geotarget.canonical_name.clone())
+    }
+}
+
+/// Split a single CSV line into fields, honoring double-quoted fields that may contain commas.
+fn parse_csv_line(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => {
+                fields.push(std::mem::take(&mut field));
+            }
+            c => field.push(c),
+        }
+    }
+    fields.push(field);
+
+    fields
+}
+
+/// GeotargetsError is the error type for [`Geotargets::from_csv_reader`].
+#[derive(Error, Debug)]
+pub enum GeotargetsError {
+    /// Underlying I/O error while reading the CSV. Underlying error is accessible as
+    /// `error.source`
+    #[error("I/O error reading geotarget CSV. Underlying error: {source}")]
+    Io {
+        #[from] source: std::io::Error
+    },
+    /// A row didn't have enough fields. The line number (0-indexed, including the header) is
+    /// accessible as `error.0`
+    #[error("Malformed geotarget row at line {0}")]
+    MalformedRow(usize),
+    /// The criteria ID field wasn't a valid integer. Underlying error is accessible as
+    /// `error.source`
+    #[error("Invalid criteria ID. Underlying error: {source}")]
+    InvalidCriteriaId {
+        #[from] source: std::num::ParseIntError
+    },
+}
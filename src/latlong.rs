@@ -1,3 +1,5 @@
+use thiserror::Error;
+
 /// Convert a latitude or longitude to an e7 integer representation.
 /// UULE encodes these values as integers raised to the 7th power.
 ///
@@ -28,4 +30,153 @@ pub fn latlong_to_e7(input: f64) -> i64 {
 /// ```
 pub fn latlong_from_e7(input: i64) -> f64 {
     input as f64 / 10_000_000.0
+}
+
+/// Parse a latitude or longitude written as degrees/minutes/seconds with a trailing hemisphere
+/// letter, e.g. `"37 25 15.6 N"` or `"122 05 06.2 W"` - the same convention DNS LOC records use.
+///
+/// The string is three whitespace-separated numeric fields - degrees (integer), minutes
+/// (integer), seconds (float) - followed by one of `N`/`S`/`E`/`W`. Minutes and seconds are
+/// optional and default to `0` when absent. The hemisphere determines the sign of the result
+/// (`S` and `W` are negative) and whether the value is validated as a latitude (magnitude <= 90)
+/// or a longitude (magnitude <= 180).
+///
+/// # Examples
+///
+/// ```
+/// use uule_converter::latlong::latlong_from_dms;
+/// use uule_converter::latlong::LatLongError;
+/// assert_eq!(latlong_from_dms("37 25 15.6 N").unwrap(), 37.421);
+/// assert_eq!(latlong_from_dms("12 12 30.2 S").unwrap(), -12.208388888888889);
+/// assert_eq!(latlong_from_dms("90 N").unwrap(), 90.0);
+/// assert_eq!(latlong_from_dms("-10 S").unwrap_err(), LatLongError::NegativeDegrees("-10 S".to_string()));
+/// ```
+pub fn latlong_from_dms(input: &str) -> Result<f64, LatLongError> {
+    let mut fields: Vec<&str> = input.split_whitespace().collect();
+    let hemisphere = fields.pop().ok_or_else(|| LatLongError::InvalidFormat(input.to_string()))?;
+    if fields.is_empty() || fields.len() > 3 {
+        return Err(LatLongError::InvalidFormat(input.to_string()));
+    }
+
+    let degrees: i64 = fields[0].parse().map_err(|source| LatLongError::InvalidIntegerValue { source })?;
+    if degrees < 0 {
+        return Err(LatLongError::NegativeDegrees(input.to_string()));
+    }
+    let minutes: u32 = match fields.get(1) {
+        Some(field) => field.parse().map_err(|source| LatLongError::InvalidIntegerValue { source })?,
+        None => 0,
+    };
+    let seconds: f64 = match fields.get(2) {
+        Some(field) => field.parse().map_err(|source| LatLongError::InvalidFloatValue { source })?,
+        None => 0.0,
+    };
+
+    if minutes >= 60 || seconds >= 60.0 {
+        return Err(LatLongError::InvalidMinutesOrSeconds(input.to_string()));
+    }
+
+    let sign = match hemisphere {
+        "N" | "E" => 1.0,
+        "S" | "W" => -1.0,
+        _ => return Err(LatLongError::InvalidHemisphere(hemisphere.to_string())),
+    };
+    let value = sign * (degrees as f64 + minutes as f64 / 60.0 + seconds / 3600.0);
+
+    match hemisphere {
+        "N" | "S" if value.abs() > 90.0 => Err(LatLongError::LatitudeOutOfRange(value)),
+        "E" | "W" if value.abs() > 180.0 => Err(LatLongError::LongitudeOutOfRange(value)),
+        _ => Ok(value),
+    }
+}
+
+/// Format a latitude or longitude as a degrees/minutes/seconds string with a trailing hemisphere
+/// letter, the inverse of [`latlong_from_dms`]. Seconds are emitted to one decimal place.
+///
+/// # Examples
+///
+/// ```
+/// use uule_converter::latlong::latlong_to_dms;
+/// assert_eq!(latlong_to_dms(37.421, false), "37 25 15.6 N");
+/// assert_eq!(latlong_to_dms(-122.0840556, true), "122 05 02.6 W");
+///
+/// // Seconds within float epsilon of 60 round up into minutes (and minutes into degrees)
+/// // instead of printing an invalid "60.0 seconds" that latlong_from_dms would reject.
+/// assert_eq!(latlong_to_dms(30.94999999999997, false), "30 57 00.0 N");
+/// ```
+pub fn latlong_to_dms(value: f64, is_longitude: bool) -> String {
+    let abs_value = value.abs();
+    let mut degrees = abs_value.trunc() as i64;
+    let minutes_fraction = (abs_value - degrees as f64) * 60.0;
+    let mut minutes = minutes_fraction.trunc() as i64;
+    let mut seconds = ((minutes_fraction - minutes as f64) * 60.0 * 10.0).round() / 10.0;
+
+    if seconds >= 60.0 {
+        seconds -= 60.0;
+        minutes += 1;
+    }
+    if minutes >= 60 {
+        minutes -= 60;
+        degrees += 1;
+    }
+
+    let hemisphere = if is_longitude {
+        if value < 0.0 { "W" } else { "E" }
+    } else if value < 0.0 { "S" } else { "N" };
+
+    format!("{degrees} {minutes:02} {seconds:04.1} {hemisphere}")
+}
+
+/// LatLongError is an enum that represents the possible errors that can occur when parsing a
+/// degrees/minutes/seconds coordinate string with [`latlong_from_dms`].
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum LatLongError {
+    /// Invalid DMS format. A DMS string must contain between one and three numeric fields
+    /// followed by a hemisphere letter. Received string is accessible as `error.0`
+    #[error("Invalid DMS coordinate format. Received: {0}")]
+    InvalidFormat(String),
+    /// Invalid hemisphere letter. Must be one of `N`, `S`, `E`, or `W`. Received string is
+    /// accessible as `error.0`
+    #[error("Invalid hemisphere. Must be one of N, S, E, W. Received: {0}")]
+    InvalidHemisphere(String),
+    /// The degrees field was signed. Sign is conveyed by the hemisphere letter only, so a
+    /// negative degrees field (e.g. `"-10 S"`) is rejected rather than silently flipping sign.
+    /// Received string is accessible as `error.0`
+    #[error("Degrees field must not be signed, sign comes from the hemisphere letter. Received: {0}")]
+    NegativeDegrees(String),
+    /// Minutes or seconds were out of range (must both be less than 60). Received string is
+    /// accessible as `error.0`
+    #[error("Minutes or seconds out of range. Received: {0}")]
+    InvalidMinutesOrSeconds(String),
+    /// Resulting latitude had a magnitude greater than 90. Received value is accessible as
+    /// `error.0`
+    #[error("Latitude out of range, must be between -90 and 90. Received: {0}")]
+    LatitudeOutOfRange(f64),
+    /// Resulting longitude had a magnitude greater than 180. Received value is accessible as
+    /// `error.0`
+    #[error("Longitude out of range, must be between -180 and 180. Received: {0}")]
+    LongitudeOutOfRange(f64),
+    /// Invalid value while parsing a supposed integer. Underlying error is accessible as
+    /// `error.source`
+    #[error("Invalid integer value. Underlying error: {source}")]
+    InvalidIntegerValue {
+        #[from] source: std::num::ParseIntError
+    },
+    /// Invalid value while parsing a supposed float. Underlying error is accessible as
+    /// `error.source`
+    #[error("Invalid float value. Underlying error: {source}")]
+    InvalidFloatValue {
+        #[from] source: std::num::ParseFloatError
+    },
+}
+
+/// Serializes as the error's Display message. There's no matching `Deserialize` impl, since
+/// reconstructing the original error variant from a message string isn't meaningful.
+#[cfg(feature = "serde")]
+impl serde::Serialize for LatLongError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
 }
\ No newline at end of file
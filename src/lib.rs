@@ -9,6 +9,10 @@
 //! UULEv1 in Python: <https://github.com/ogun/uule_grabber>
 //! UULEv2 in Ruby: <https://github.com/serpapi/uule_converter>
 //!
+//! Enable the `serde` feature to derive `Serialize`/`Deserialize` for [`Uulev1Data`],
+//! [`Uulev2Data`], and [`Uule`], so UULE data can round-trip through JSON. Coordinates serialize
+//! as human-readable `f64` degrees rather than the wire `*_e7` integers.
+//!
 //! # Examples
 //!
 //! ## UULEv1
@@ -53,3 +57,91 @@ pub mod uulev2;
 pub mod latlong;
 /// Contains the constants used in the UULEv1 and UULEv2 implementations
 pub mod consts;
+/// Contains the geotarget resolution subsystem for looking up UULEv1 canonical names
+pub mod geotargets;
+
+use thiserror::Error;
+use uulev1::{Uulev1Data, Uulev1Error};
+use uulev2::{Uulev2Data, Uulev2Error};
+
+/// Uule is an enum that wraps either a [`Uulev1Data`] or a [`Uulev2Data`], letting callers
+/// decode a UULE string of unknown version with a single entry point.
+///
+/// [`Uule::decode`] inspects the string's prefix (`w+` for UULEv1, `a+` for UULEv2) and
+/// dispatches to the matching decoder. A prefix that doesn't match either known version decodes
+/// into [`Uule::Unknown`] instead of failing, so strings from a future UULE version can still be
+/// round-tripped through [`Uule::encode`] without data loss.
+///
+/// # Examples
+///
+/// ```
+/// use uule_converter::Uule;
+/// use uule_converter::uulev1::Uulev1Data;
+///
+/// let uule = Uule::decode("w+CAIQICIkUXVlZW5zIENvdW50eSxOZXcgWW9yayxVbml0ZWQgU3RhdGVz").unwrap();
+/// assert_eq!(uule, Uule::V1(Uulev1Data { role: 2, producer: 32, canonical_name: "Queens County,New York,United States".to_string() }));
+///
+/// let uule = Uule::decode("z+whatever").unwrap();
+/// assert_eq!(uule, Uule::Unknown { prefix: "z".to_string(), raw: "z+whatever".to_string() });
+/// assert_eq!(uule.encode(), "z+whatever");
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, PartialEq, Clone)]
+pub enum Uule {
+    /// A decoded UULEv1 string
+    V1(Uulev1Data),
+    /// A decoded UULEv2 string
+    V2(Uulev2Data),
+    /// A string whose prefix didn't match a known UULE version. The prefix (everything before
+    /// the first `+`) is accessible as `prefix`, and the original string is kept as `raw` so it
+    /// can be round-tripped by `encode()`.
+    Unknown { prefix: String, raw: String },
+}
+
+impl Uule {
+    /// Decode a UULE string of unknown version, dispatching on its prefix.
+    pub fn decode(input: &str) -> Result<Self, UuleError> {
+        if input.starts_with("w+") {
+            Ok(Uule::V1(Uulev1Data::decode(input)?))
+        } else if input.starts_with("a+") {
+            Ok(Uule::V2(Uulev2Data::decode(input)?))
+        } else {
+            let prefix = input.split('+').next().unwrap_or_default().to_string();
+            Ok(Uule::Unknown { prefix, raw: input.to_string() })
+        }
+    }
+
+    /// Encode back into a UULE string, delegating to the wrapped version's `encode()`. An
+    /// [`Uule::Unknown`] value simply returns its original raw string.
+    pub fn encode(&self) -> String {
+        match self {
+            Uule::V1(data) => data.encode(),
+            Uule::V2(data) => data.encode(),
+            Uule::Unknown { raw, .. } => raw.clone(),
+        }
+    }
+}
+
+/// UuleError is the unified error type for [`Uule::decode`], wrapping the version-specific
+/// decoding errors.
+#[derive(Error, Debug, PartialEq)]
+pub enum UuleError {
+    /// Error decoding a `w+` prefixed UULEv1 string. Underlying error is accessible as `error.0`
+    #[error("Error decoding UULEv1 string: {0}")]
+    V1(#[from] Uulev1Error),
+    /// Error decoding an `a+` prefixed UULEv2 string. Underlying error is accessible as `error.0`
+    #[error("Error decoding UULEv2 string: {0}")]
+    V2(#[from] Uulev2Error),
+}
+
+/// Serializes as the error's Display message. There's no matching `Deserialize` impl, since
+/// reconstructing the original error variant from a message string isn't meaningful.
+#[cfg(feature = "serde")]
+impl serde::Serialize for UuleError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
@@ -37,9 +37,15 @@ pub type Uulev1 = String;
 /// let uule = Uulev1Data::decode("w+CAIQICIkUXVlZW5zIENvdW50eSxOZXcgWW9yayxVbml0ZWQgU3RhdGVz ").unwrap_err(); // trailing whitespace makes it invalid base64
 /// assert_eq!(uule, Uulev1Error::Base64DecodingError { source: base64::DecodeError::InvalidByte(56, 32) });
 ///
-/// let uule = Uulev1Data::decode("w+CAIQICIkUXVlZW5zIENvdW50eSxOZXcgWW9yayxVbml0ZWQgU3RhdGVz").unwrap_err(); // trailing whitespace
-/// assert_eq!(uule, Uulev1Error::Base64DecodingError { source: base64::DecodeError::InvalidByte(56, 32) });
+/// let uule = Uulev1Data::decode("w+CAIQICIkUXVlZW5zIENvdW50eSxOZXcgWW9yayxVbml0ZWQgU3RhdGVz").unwrap(); // no trailing whitespace this time
+/// assert_eq!(uule, Uulev1Data { role: 2, producer: 32, canonical_name: "Queens County,New York,United States".to_string() });
+///
+/// // A field-length varint that claims more bytes than remain in the message (here, exactly
+/// // u64::MAX) must not panic on the bounds-check arithmetic - it should report UnexpectedEnd.
+/// let uule = Uulev1Data::decode("w+Iv___________wE").unwrap_err();
+/// assert_eq!(uule, Uulev1Error::UnexpectedEnd("length-delimited field".to_string()));
 /// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, PartialEq, Clone)]
 pub struct Uulev1Data {
     pub role: u8,
@@ -53,9 +59,15 @@ impl Uulev1Data {
     }
 
     pub fn encode(&self) -> Uulev1 {
-        let mut name_bytes = self.canonical_name.as_bytes().to_vec();
-        let mut bytes: Vec<u8> = vec![8, self.role, 16, self.producer, 34, self.canonical_name.len() as u8];
-        bytes.append(&mut name_bytes);
+        let mut bytes: Vec<u8> = Vec::new();
+        bytes.push(8); // field 1 (role), wire type 0 (varint)
+        bytes.extend(encode_varint(self.role as u64));
+        bytes.push(16); // field 2 (producer), wire type 0 (varint)
+        bytes.extend(encode_varint(self.producer as u64));
+        bytes.push(34); // field 4 (canonical_name), wire type 2 (length-delimited)
+        let name_bytes = self.canonical_name.as_bytes();
+        bytes.extend(encode_varint(name_bytes.len() as u64));
+        bytes.extend_from_slice(name_bytes);
         format!("w+{}", base64_url::encode(&bytes))
     }
 
@@ -65,12 +77,81 @@ impl Uulev1Data {
         }
         let input = input.trim_start_matches("w+");
         let bytes = base64_url::decode(input)?;
-        let role = bytes[1];
-        let producer = bytes[3];
-        let name_len = bytes[5] as usize;
-        let name = String::from_utf8(bytes[6..6 + name_len].to_vec());
-        Ok(Self { role, producer, canonical_name: name? })
+
+        let mut role = UULEV1_ROLE;
+        let mut producer = UULEV1_PRODUCER;
+        let mut canonical_name = String::new();
+
+        let mut pos = 0;
+        while pos < bytes.len() {
+            let (tag, new_pos) = decode_varint(&bytes, pos)?;
+            pos = new_pos;
+            let field_number = tag >> 3;
+            let wire_type = tag & 7;
+
+            match wire_type {
+                0 => {
+                    let (value, new_pos) = decode_varint(&bytes, pos)?;
+                    pos = new_pos;
+                    match field_number {
+                        1 => role = value as u8,
+                        2 => producer = value as u8,
+                        _ => {}
+                    }
+                }
+                2 => {
+                    let (len, new_pos) = decode_varint(&bytes, pos)?;
+                    pos = new_pos;
+                    let len = len as usize;
+                    let end = pos.checked_add(len).ok_or_else(|| Uulev1Error::UnexpectedEnd("length-delimited field".to_string()))?;
+                    let field_bytes = bytes.get(pos..end).ok_or_else(|| Uulev1Error::UnexpectedEnd("length-delimited field".to_string()))?;
+                    pos = end;
+                    if field_number == 4 {
+                        canonical_name = String::from_utf8(field_bytes.to_vec())?;
+                    }
+                }
+                _ => return Err(Uulev1Error::UnsupportedWireType(wire_type as u8)),
+            }
+        }
+
+        Ok(Self { role, producer, canonical_name })
+    }
+}
+
+/// Encode a value as a protobuf varint (LEB128, 7 bits per byte, low bits first, high bit set on
+/// every byte but the last).
+fn encode_varint(mut value: u64) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    loop {
+        let byte = (value & 0x7F) as u8;
+        value >>= 7;
+        if value == 0 {
+            bytes.push(byte);
+            break;
+        }
+        bytes.push(byte | 0x80);
+    }
+    bytes
+}
+
+/// Decode a protobuf varint starting at `pos`, returning the value and the position just past it.
+fn decode_varint(bytes: &[u8], pos: usize) -> Result<(u64, usize), Uulev1Error> {
+    let mut result: u64 = 0;
+    let mut shift = 0;
+    let mut i = pos;
+    loop {
+        let byte = *bytes.get(i).ok_or_else(|| Uulev1Error::UnexpectedEnd("varint".to_string()))?;
+        result |= ((byte & 0x7F) as u64) << shift;
+        i += 1;
+        if byte & 0x80 == 0 {
+            break;
+        }
+        shift += 7;
+        if shift >= 64 {
+            return Err(Uulev1Error::InvalidVarint);
+        }
     }
+    Ok((result, i))
 }
 
 /// Uulev1Error is an enum that represents the possible errors that can occur when decoding a UULEv1 string.
@@ -89,4 +170,27 @@ pub enum Uulev1Error {
     Utf8DecodingError {
         #[from] source: std::string::FromUtf8Error
     },
+    /// Unexpected end of input while decoding a protobuf field. The field being decoded is
+    /// accessible as `error.0`
+    #[error("Unexpected end of input while decoding {0}")]
+    UnexpectedEnd(String),
+    /// A protobuf varint used more bytes than fit in a 64-bit value
+    #[error("Invalid varint encoding")]
+    InvalidVarint,
+    /// An unsupported protobuf wire type was encountered. Only varint (0) and length-delimited
+    /// (2) are supported. Received wire type is accessible as `error.0`
+    #[error("Unsupported protobuf wire type: {0}")]
+    UnsupportedWireType(u8),
+}
+
+/// Serializes as the error's Display message. There's no matching `Deserialize` impl, since
+/// reconstructing the original error variant from a message string isn't meaningful.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Uulev1Error {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
 }
\ No newline at end of file
@@ -55,6 +55,7 @@ pub type Uulev2 = String;
 ///
 ///
 /// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, PartialEq, Clone)]
 pub struct Uulev2Data {
     pub role: u8,
@@ -125,6 +126,25 @@ impl Uulev2Data {
         self
     }
 
+    /// Set the latitude and longitude from degrees/minutes/seconds strings, e.g.
+    /// `"37 25 15.6 N"` / `"122 05 06.2 W"`, the same textual convention DNS LOC records use.
+    /// See [`latlong::latlong_from_dms`] for the accepted format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uule_converter::uulev2::Uulev2Data;
+    ///
+    /// let uule = Uulev2Data::default().with_coords_str("37 25 15.6 N", "122 05 06.2 W").unwrap();
+    /// assert_eq!(uule.lat, 37.421);
+    /// assert_eq!(uule.long, -122.08505555555556);
+    /// ```
+    pub fn with_coords_str(mut self, lat: &str, long: &str) -> Result<Self, Uulev2Error> {
+        self.lat = latlong::latlong_from_dms(lat).map_err(|source| Uulev2Error::InvalidCoordinates { source })?;
+        self.long = latlong::latlong_from_dms(long).map_err(|source| Uulev2Error::InvalidCoordinates { source })?;
+        Ok(self)
+    }
+
     pub fn encode(&self) -> Uulev2 {
         format!("a+{}", base64_url::encode(&self.to_string()))
     }
@@ -134,8 +154,8 @@ impl Uulev2Data {
             return Err(Uulev2Error::InvalidPrefix(input.to_string()));
         }
         let input = input.trim_start_matches("a+");
-        let decoded = base64_url::decode(input).unwrap();
-        let decoded = String::from_utf8(decoded).unwrap();
+        let decoded = base64_url::decode(input)?;
+        let decoded = String::from_utf8(decoded)?;
         let mut lines = decoded.lines();
 
         let role: u8 = Uulev2Data::parse_int_line(lines.next(), "role")?;
@@ -165,8 +185,8 @@ impl Uulev2Data {
         if line != "latlng{" {
             return Err(Uulev2Error::UnexpectedLine{expected: "latlng{".to_string(), actual: line.to_string()});
         }
-        let lat: f64 = latlong::latlong_from_e7(Uulev2Data::parse_int_line(lines.next(), "latitude_e7")?);
-        let long: f64 = latlong::latlong_from_e7(Uulev2Data::parse_int_line(lines.next(), "longitude_e7")?);
+        let lat: f64 = Uulev2Data::parse_coord_line(lines.next(), "latitude")?;
+        let long: f64 = Uulev2Data::parse_coord_line(lines.next(), "longitude")?;
         let line = lines.next().ok_or_else(|| Uulev2Error::UnexpectedEnd("}".to_string()))?;
         if line != "}" {
             return Err(Uulev2Error::UnexpectedLine{expected: "}".to_string(), actual: line.to_string()});
@@ -175,6 +195,21 @@ impl Uulev2Data {
         Ok((lat, long))
     }
 
+    /// Parse a `latlng{}` coordinate line, accepting either the `{field}_e7` integer form
+    /// UULEv2 normally encodes or a plain `{field}` decimal-degree line.
+    fn parse_coord_line(line: Option<&str>, field: &str) -> Result<f64, Uulev2Error> {
+        let e7_field = format!("{field}_e7");
+        let checked_line = line.ok_or_else(|| Uulev2Error::UnexpectedEnd(e7_field.clone()))?;
+        if checked_line.starts_with(&e7_field) {
+            let value: i64 = Uulev2Data::parse_int_line(line, &e7_field)?;
+            Ok(latlong::latlong_from_e7(value))
+        } else if checked_line.starts_with(field) {
+            Uulev2Data::get_field_value(line, field)?.parse::<f64>().map_err(|e| Uulev2Error::InvalidFloatValue { source: e })
+        } else {
+            Err(Uulev2Error::UnexpectedLine { expected: e7_field, actual: checked_line.to_string() })
+        }
+    }
+
     fn get_field_value<'a>(line: Option<&'a str>, field: &str) -> Result<&'a str, Uulev2Error> {
         let line = line.ok_or_else(|| Uulev2Error::UnexpectedEnd(field.to_string()))?;
         if !line.starts_with(field) {
@@ -192,7 +227,14 @@ pub enum Uulev2Error {
     InvalidPrefix(String),
     /// Invalid Base64-URL string. Underlying error is accessible as `error.source`
     #[error("Invalid Base64-URL string. Underlying error: {source}")]
-    Base64DecodingError { source: base64::DecodeError },
+    Base64DecodingError {
+        #[from] source: base64::DecodeError
+    },
+    /// Invalid UTF-8 string. Underlying error is accessible as `error.source`
+    #[error("Invalid UTF-8 string. Underlying error: {source}")]
+    Utf8DecodingError {
+        #[from] source: std::string::FromUtf8Error
+    },
     /// Unexpected end of string while decoding. Expected line is accessible as `error.0`
     #[error("Unexpected end of string, expected line {0}")]
     UnexpectedEnd(String),
@@ -211,4 +253,22 @@ pub enum Uulev2Error {
     /// Invalid value while passing a supposed float. Underlying error is accessible as `error.source`
     #[error("Invalid float value. Underlying error: {source}")]
     InvalidFloatValue { source: std::num::ParseFloatError },
+    /// Invalid DMS coordinate string passed to [`Uulev2Data::with_coords_str`]. Underlying error
+    /// is accessible as `error.source`
+    #[error("Invalid coordinate string. Underlying error: {source}")]
+    InvalidCoordinates {
+        #[from] source: latlong::LatLongError
+    },
+}
+
+/// Serializes as the error's Display message. There's no matching `Deserialize` impl, since
+/// reconstructing the original error variant from a message string isn't meaningful.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Uulev2Error {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
 }
\ No newline at end of file